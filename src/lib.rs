@@ -2,27 +2,218 @@
 extern crate log;
 
 use structopt::StructOpt;
-use std::io::{BufReader, Read, BufWriter, Write};
+use structopt::clap::arg_enum;
+use std::io::{BufReader, BufRead, Read, BufWriter, Write, Seek, SeekFrom};
 use std::fs::File;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use ascii_utils::Check;
 use indicatif::{ProgressBar, ProgressStyle};
 
+arg_enum! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub enum ColorMode {
+        Auto,
+        Always,
+        Never,
+    }
+}
+
+// Hand-rolled instead of `arg_enum!`: the CLI values are dashed ("hex-upper")
+// but arg_enum! matches a variant's Rust identifier case-insensitively as-is,
+// so it can't produce "hex-upper" from a `HexUpper` variant.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NumericFormat {
+    HexUpper,
+    HexLower,
+    Octal,
+    Binary,
+    Decimal,
+}
+
+impl std::str::FromStr for NumericFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hex-upper" => Ok(NumericFormat::HexUpper),
+            "hex-lower" => Ok(NumericFormat::HexLower),
+            "octal" => Ok(NumericFormat::Octal),
+            "binary" => Ok(NumericFormat::Binary),
+            "decimal" => Ok(NumericFormat::Decimal),
+            _ => Err(format!("invalid format '{}', expected one of: hex-upper, hex-lower, octal, binary, decimal", s)),
+        }
+    }
+}
+
+fn format_width(format: NumericFormat) -> usize {
+    match format {
+        NumericFormat::HexUpper | NumericFormat::HexLower => 2,
+        NumericFormat::Octal => 3,
+        NumericFormat::Binary => 8,
+        NumericFormat::Decimal => 3,
+    }
+}
+
+fn byte_to_format(byte: &u8, format: NumericFormat) -> String {
+    match format {
+        NumericFormat::HexUpper => format!("{:02X}", byte),
+        NumericFormat::HexLower => format!("{:02x}", byte),
+        NumericFormat::Octal => format!("{:03o}", byte),
+        NumericFormat::Binary => format!("{:08b}", byte),
+        NumericFormat::Decimal => format!("{:03}", byte),
+    }
+}
+
+arg_enum! {
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub enum ArrayLang {
+        C,
+        Rust,
+        Python,
+        Go,
+    }
+}
+
 #[derive(Debug, StructOpt)]
 #[structopt(name="hex_dump", about="Creates a file dump in hex and ascii format")]
 pub struct CommandLine {
     #[structopt(short="i", long="input")]
-    input : std::path::PathBuf,
+    input : Option<std::path::PathBuf>,
+
+    #[structopt(short="o", long="output")]
+    output : Option<std::path::PathBuf>,
 
     #[structopt(short="c", long="columns", default_value="16")]
     columns : usize,
+
+    #[structopt(long="color", possible_values=&ColorMode::variants(), case_insensitive=true, default_value="auto")]
+    color : ColorMode,
+
+    #[structopt(long="array", possible_values=&ArrayLang::variants(), case_insensitive=true)]
+    array : Option<ArrayLang>,
+
+    #[structopt(long="name", alias="func", default_value="DATA")]
+    name : String,
+
+    #[structopt(long="format", possible_values=&["hex-upper", "hex-lower", "octal", "binary", "decimal"], case_insensitive=true, default_value="hex-upper")]
+    format : NumericFormat,
+
+    #[structopt(long="skip", parse(try_from_str = parse_size), default_value="0")]
+    skip : u64,
+
+    #[structopt(long="length", parse(try_from_str = parse_size))]
+    length : Option<u64>,
+
+    #[structopt(long="detect")]
+    detect : bool,
+}
+
+struct Signature {
+    bytes: &'static [u8],
+    mask: &'static [u8],
+    label: &'static str,
+}
+
+const SIGNATURES: &[Signature] = &[
+    Signature { bytes: &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A], mask: &[0xFF; 8], label: "PNG image" },
+    Signature { bytes: &[0x25, 0x50, 0x44, 0x46], mask: &[0xFF; 4], label: "PDF document" },
+    Signature { bytes: &[0x7F, 0x45, 0x4C, 0x46], mask: &[0xFF; 4], label: "ELF binary" },
+    Signature { bytes: &[0x50, 0x4B, 0x03, 0x04], mask: &[0xFF; 4], label: "ZIP archive" },
+    Signature { bytes: &[0x1F, 0x8B], mask: &[0xFF; 2], label: "GZIP archive" },
+];
+
+fn detect_signature(data: &[u8]) -> Option<&'static str> {
+    SIGNATURES.iter().find(|sig| {
+        sig.bytes.len() <= data.len()
+            && sig.bytes.iter().zip(sig.mask.iter()).zip(data.iter())
+                .all(|((byte, mask), actual)| (byte & mask) == (actual & mask))
+    }).map(|sig| sig.label)
+}
+
+fn parse_size(s: &str) -> std::result::Result<u64, String> {
+    let s = s.trim();
+
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        return u64::from_str_radix(hex, 16).map_err(|e| e.to_string());
+    }
+
+    let (digits, multiplier) = match s.chars().last() {
+        Some('K') | Some('k') => (&s[..s.len()-1], 1024u64),
+        Some('M') | Some('m') => (&s[..s.len()-1], 1024u64 * 1024),
+        Some('G') | Some('g') => (&s[..s.len()-1], 1024u64 * 1024 * 1024),
+        _ => (s, 1u64),
+    };
+
+    let n = digits.parse::<u64>().map_err(|e| e.to_string())?;
+    n.checked_mul(multiplier).ok_or_else(|| format!("size too large: {}", s))
 }
 
 impl CommandLine{
     pub fn valid(&self) -> bool{
         [8usize,16usize,32usize,64usize].contains(&self.columns)
     }
+
+    fn reads_stdin(&self) -> bool {
+        match &self.input {
+            None => true,
+            Some(path) => is_dash(path),
+        }
+    }
+
+    fn writes_stdout(&self) -> bool {
+        match &self.output {
+            Some(path) => is_dash(path),
+            None => self.reads_stdin(),
+        }
+    }
+}
+
+fn is_dash(path: &std::path::Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+enum Source {
+    File(BufReader<File>),
+    Stdin(BufReader<std::io::Stdin>),
+}
+
+impl Read for Source {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Source::File(reader) => reader.read(buf),
+            Source::Stdin(reader) => reader.read(buf),
+        }
+    }
+}
+
+impl BufRead for Source {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        match self {
+            Source::File(reader) => reader.fill_buf(),
+            Source::Stdin(reader) => reader.fill_buf(),
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        match self {
+            Source::File(reader) => reader.consume(amt),
+            Source::Stdin(reader) => reader.consume(amt),
+        }
+    }
+}
+
+fn skip_bytes(reader: &mut impl Read, mut count: u64) -> std::io::Result<()> {
+    let mut buf = [0u8; 4096];
+    while count > 0 {
+        let want = usize::min(buf.len(), count as usize);
+        let bytes_read = reader.read(&mut buf[0..want])?;
+        if bytes_read == 0 {
+            break;
+        }
+        count -= bytes_read as u64;
+    }
+    Ok(())
 }
 
 type Result<T> = std::result::Result<T, Box<dyn Error>>;
@@ -40,50 +231,143 @@ impl Display for IoError {
 
 impl Error for IoError{}
 
-pub fn dump(cli: CommandLine) -> Result<()> {
-
-    let mut output = cli.input.clone();
-    output.set_extension("dump");
+fn color_enabled(mode: ColorMode, stdout: bool) -> bool {
+    if std::env::var_os("NO_COLOR").is_some() {
+        return false;
+    }
 
-    let file_input = File::open(&cli.input).map_err(|e| {
-        let message = format!("{} : {:?}", e, &cli.input);
-        Box::new(IoError { message })
-    })?;
+    match mode {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => stdout && atty::is(atty::Stream::Stdout),
+    }
+}
 
-    let bar = ProgressBar::new(file_input.metadata().unwrap().len() as u64);
-    bar.set_style(ProgressStyle::default_bar()
-        .template("[{eta_precise}] {bar:40.cyan/blue} {bytes:>7}/{total_bytes:7} {msg}")
-        .progress_chars("##-"));
+pub fn dump(cli: CommandLine) -> Result<()> {
 
-    let mut reader = BufReader::new(file_input);
+    if let Some(lang) = cli.array {
+        return array_dump(&cli, lang);
+    }
 
-    let file_output =  File::create(&output).map_err(|e| {
-        let message = format!("{} : {:?}", e, output);
-        Box::new(IoError { message })
-    })?;
+    let stdin = cli.reads_stdin();
+    let stdout = cli.writes_stdout();
+    let colorize = color_enabled(cli.color, stdout);
+
+    let (mut reader, file_len) = if stdin {
+        (Source::Stdin(BufReader::new(std::io::stdin())), None)
+    } else {
+        let path = cli.input.as_ref().unwrap();
+        let file_input = File::open(path).map_err(|e| {
+            let message = format!("{} : {:?}", e, path);
+            Box::new(IoError { message })
+        })?;
+        let len = file_input.metadata().unwrap().len();
+        (Source::File(BufReader::new(file_input)), Some(len))
+    };
+
+    let total = match (file_len, cli.length) {
+        (Some(len), Some(length)) => Some(length.min(len.saturating_sub(cli.skip))),
+        (Some(len), None) => Some(len.saturating_sub(cli.skip)),
+        (None, Some(length)) => Some(length),
+        (None, None) => None,
+    };
+
+    let bar = if stdout && !atty::is(atty::Stream::Stdout) {
+        ProgressBar::hidden()
+    } else if let Some(total) = total {
+        let bar = ProgressBar::new(total);
+        bar.set_style(ProgressStyle::default_bar()
+            .template("[{eta_precise}] {bar:40.cyan/blue} {bytes:>7}/{total_bytes:7} {msg}")
+            .progress_chars("##-"));
+        bar
+    } else {
+        let bar = ProgressBar::new_spinner();
+        bar.set_style(ProgressStyle::default_spinner()
+            .template("{spinner} {bytes} read {msg}"));
+        bar
+    };
+
+    let detected = if cli.detect {
+        let peek = reader.fill_buf().map_err(|e| {
+            let message = format!("{} : {:?}", e, cli.input);
+            Box::new(IoError { message })
+        })?;
+        detect_signature(peek)
+    } else {
+        None
+    };
+
+    if cli.skip > 0 {
+        match &mut reader {
+            Source::File(r) => {
+                r.seek(SeekFrom::Start(cli.skip)).map_err(|e| {
+                    let message = format!("{} : {:?}", e, cli.input);
+                    Box::new(IoError { message })
+                })?;
+            }
+            Source::Stdin(r) => {
+                skip_bytes(r, cli.skip).map_err(|e| {
+                    let message = format!("{} : {:?}", e, cli.input);
+                    Box::new(IoError { message })
+                })?;
+            }
+        }
+    }
 
-    let mut writer = BufWriter::new(file_output);
+    let mut writer: Box<dyn Write> = if stdout {
+        Box::new(BufWriter::new(std::io::stdout()))
+    } else {
+        let output = match &cli.output {
+            Some(path) => path.clone(),
+            None => {
+                let mut output = cli.input.clone().unwrap();
+                output.set_extension("dump");
+                output
+            }
+        };
+        let file_output = File::create(&output).map_err(|e| {
+            let message = format!("{} : {:?}", e, output);
+            Box::new(IoError { message })
+        })?;
+        Box::new(BufWriter::new(file_output))
+    };
 
     let mut buffer: Vec<u8> = Vec::new();
     buffer.resize(cli.columns, 0);
 
-    write!(writer, "{}", locations_header(cli.columns))?;
+    if let Some(label) = detected {
+        write!(writer, "# detected: {}\n", label)?;
+    }
+
+    write!(writer, "{}", locations_header(cli.columns, cli.format))?;
 
-    let mut address = 0;
+    let mut address = cli.skip;
+    let mut remaining = total;
     loop {
-        match reader.read(&mut buffer){
+        if remaining == Some(0) {
+            info!("reached requested length");
+            bar.finish_and_clear();
+            return Ok(());
+        }
+
+        let want = match remaining {
+            Some(remaining) => usize::min(cli.columns, remaining as usize),
+            None => cli.columns,
+        };
+        match reader.read(&mut buffer[0..want]){
             Ok(bytes_read) if bytes_read == 0 => {
                 info!("EOF");
                 bar.finish_and_clear();
                 return Ok(());
             }
             Ok(bytes_read) => {
-                if address % (16 * cli.columns as u32) == 0 {
+                if address % (16 * cli.columns as u64) == 0 {
                     write!(writer, "\n")?;
                 }
                 let slice = &buffer[0..bytes_read];
-                let row = data_row(address, slice, cli.columns);
-                address += bytes_read as u32;
+                let row = data_row(address, slice, cli.columns, colorize, cli.format);
+                address += bytes_read as u64;
+                remaining = remaining.map(|r| r - bytes_read as u64);
                 write!(writer, "{}", row)?;
                 bar.inc(bytes_read as u64);
             }
@@ -96,7 +380,103 @@ pub fn dump(cli: CommandLine) -> Result<()> {
     }
 }
 
-fn gen_block(data: &[u8], fun : fn(&u8) -> String, columns: usize, sep: &str, filler: &str) -> Vec<String> {
+fn array_dump(cli: &CommandLine, lang: ArrayLang) -> Result<()> {
+
+    let mut data: Vec<u8> = Vec::new();
+
+    if cli.reads_stdin() {
+        std::io::stdin().read_to_end(&mut data).map_err(|e| {
+            let message = format!("{} : stdin", e);
+            Box::new(IoError { message })
+        })?;
+    } else {
+        let path = cli.input.as_ref().unwrap();
+        let file_input = File::open(path).map_err(|e| {
+            let message = format!("{} : {:?}", e, path);
+            Box::new(IoError { message })
+        })?;
+        BufReader::new(file_input).read_to_end(&mut data).map_err(|e| {
+            let message = format!("{} : {:?}", e, path);
+            Box::new(IoError { message })
+        })?;
+    }
+
+    let start = usize::min(data.len(), cli.skip as usize);
+    let end = match cli.length {
+        Some(length) => usize::min(data.len(), start.saturating_add(length as usize)),
+        None => data.len(),
+    };
+    let data = &data[start..end];
+
+    let mut writer: Box<dyn Write> = if cli.writes_stdout() {
+        Box::new(BufWriter::new(std::io::stdout()))
+    } else {
+        let output = match &cli.output {
+            Some(path) => path.clone(),
+            None => {
+                let mut output = cli.input.clone().unwrap();
+                output.set_extension("dump");
+                output
+            }
+        };
+        let file_output = File::create(&output).map_err(|e| {
+            let message = format!("{} : {:?}", e, output);
+            Box::new(IoError { message })
+        })?;
+        Box::new(BufWriter::new(file_output))
+    };
+
+    write!(writer, "{}", array_source(data, lang, &cli.name, cli.columns))?;
+
+    Ok(())
+}
+
+fn byte_to_hex_literal(byte: &u8) -> String {
+    format!("0x{}", byte_to_hex(byte))
+}
+
+fn byte_to_decimal(byte: &u8) -> String {
+    format!("{}", byte)
+}
+
+fn array_rows(data: &[u8], columns: usize, fun: impl Fn(&u8) -> String, indent: &str) -> String {
+    let columns = columns.max(1);
+    let mut out = String::new();
+
+    for chunk in data.chunks(columns) {
+        let cells = chunk.into_iter().map(|b| fun(b)).collect::<Vec<_>>().join(", ");
+        out.push_str(indent);
+        out.push_str(&cells);
+        out.push_str(",\n");
+    }
+
+    out
+}
+
+fn array_source(data: &[u8], lang: ArrayLang, name: &str, columns: usize) -> String {
+    match lang {
+        ArrayLang::C => {
+            let body = array_rows(data, columns, byte_to_hex_literal, "    ");
+            format!("unsigned char {}[{}] = {{\n{}}};\n", name, data.len(), body)
+        }
+        ArrayLang::Rust => {
+            let body = array_rows(data, columns, byte_to_hex_literal, "    ");
+            format!("const {}: [u8; {}] = [\n{}];\n", name, data.len(), body)
+        }
+        ArrayLang::Go => {
+            let body = array_rows(data, columns, byte_to_hex_literal, "\t");
+            format!("var {} = []byte{{\n{}}}\n", name, body)
+        }
+        ArrayLang::Python => {
+            let body = array_rows(data, columns, byte_to_decimal, "    ");
+            format!("{} = bytes([\n{}])\n", name, body)
+        }
+    }
+}
+
+fn gen_block<F>(data: &[u8], fun: F, columns: usize, sep: &str, filler: &str) -> Vec<String>
+    where F: Fn(&u8) -> String
+{
     let mut blocks : Vec<String> = Vec::new();
 
     for block in 0 .. columns / 8 {
@@ -105,7 +485,7 @@ fn gen_block(data: &[u8], fun : fn(&u8) -> String, columns: usize, sep: &str, fi
 
         let data: &[u8] = &data[start..end];
 
-        let mut result = data.into_iter().map(fun).collect::<Vec<_>>();
+        let mut result = data.into_iter().map(&fun).collect::<Vec<_>>();
         while result.len() < 8 {
             result.push(String::from(filler));
         }
@@ -118,30 +498,81 @@ fn gen_block(data: &[u8], fun : fn(&u8) -> String, columns: usize, sep: &str, fi
     blocks
 }
 
-fn locations_header(columns: usize) -> String {
+fn locations_header(columns: usize, format: NumericFormat) -> String {
 
     let data: Vec<u8> = (0..columns as u8).collect();
-    let blocks = gen_block(&data, byte_to_hex, columns, " ", "..");
+    let filler = ".".repeat(format_width(format));
+    let blocks = gen_block(&data, |b| byte_to_format(b, format), columns, " ", &filler);
     let blocks = blocks.join("  ");
 
-    format!("{0:10}  {1}  {0:text_size$}\n", " ", blocks, text_size = columns).to_lowercase();
-
     let address = format!("{:10}", " ");
     let text = format!("{:size$}", " ", size=columns);
 
     create_row(&address, &blocks, &text).to_lowercase()
 }
 
-fn data_row(address: u32, data: &[u8], columns : usize) -> String {
+fn data_row(address: u64, data: &[u8], columns : usize, colorize: bool, format: NumericFormat) -> String {
     let address = address_to_hex(address);
-    let blocks = gen_block(data, byte_to_hex, columns, " ", "..");
+    let filler = ".".repeat(format_width(format));
+    let blocks = gen_block(data, |b| hex_cell(b, colorize, format), columns, " ", &filler);
     let blocks = blocks.join("  ");
-    let texts = gen_block(data, byte_to_string, columns, "", ".");
+    let texts = gen_block(data, |b| ascii_cell(b, colorize), columns, "", ".");
     let texts = texts.join("");
 
     create_row(&address, &blocks, &texts)
 }
 
+#[derive(Debug, PartialEq, Eq)]
+enum ByteCategory {
+    Null,
+    Printable,
+    ControlOrWhitespace,
+    High,
+}
+
+fn categorize(byte: u8) -> ByteCategory {
+    match byte {
+        0x00 => ByteCategory::Null,
+        0x20..=0x7E => ByteCategory::Printable,
+        0x80..=0xFF => ByteCategory::High,
+        _ => ByteCategory::ControlOrWhitespace,
+    }
+}
+
+const COLOR_RESET: &str = "\x1b[0m";
+
+fn category_color(category: &ByteCategory) -> &'static str {
+    match category {
+        ByteCategory::Null => "\x1b[2m",
+        ByteCategory::Printable => "\x1b[32m",
+        ByteCategory::ControlOrWhitespace => "\x1b[33m",
+        ByteCategory::High => "\x1b[35m",
+    }
+}
+
+fn colorize_cell(byte: &u8, cell: String) -> String {
+    let category = categorize(*byte);
+    format!("{}{}{}", category_color(&category), cell, COLOR_RESET)
+}
+
+fn hex_cell(byte: &u8, colorize: bool, format: NumericFormat) -> String {
+    let cell = byte_to_format(byte, format);
+    if colorize {
+        colorize_cell(byte, cell)
+    } else {
+        cell
+    }
+}
+
+fn ascii_cell(byte: &u8, colorize: bool) -> String {
+    let cell = byte_to_string(byte);
+    if colorize {
+        colorize_cell(byte, cell)
+    } else {
+        cell
+    }
+}
+
 fn create_row(address: &str, data: &str, text: &str) -> String {
     format!("{}  {}  {}\n", address, data, text)
 }
@@ -159,7 +590,7 @@ fn byte_to_hex(byte: &u8) -> String {
     format!("{:02X}", byte)
 }
 
-fn address_to_hex(address: u32) -> String {
+fn address_to_hex(address: u64) -> String {
     format!("{:#010x}", address)
 }
 
@@ -171,6 +602,38 @@ mod tests{
         let _ = env_logger::builder().is_test(true).try_init();
     }
 
+    #[test]
+    fn test_array_source_c() {
+        init();
+
+        let data: [u8; 4] = [0, 1, 2, 3];
+        assert_eq!("unsigned char DATA[4] = {\n    0x00, 0x01,\n    0x02, 0x03,\n};\n", array_source(&data, ArrayLang::C, "DATA", 2));
+    }
+
+    #[test]
+    fn test_array_source_rust() {
+        init();
+
+        let data: [u8; 4] = [0, 1, 2, 3];
+        assert_eq!("const DATA: [u8; 4] = [\n    0x00, 0x01,\n    0x02, 0x03,\n];\n", array_source(&data, ArrayLang::Rust, "DATA", 2));
+    }
+
+    #[test]
+    fn test_array_source_go() {
+        init();
+
+        let data: [u8; 4] = [0, 1, 2, 3];
+        assert_eq!("var DATA = []byte{\n\t0x00, 0x01,\n\t0x02, 0x03,\n}\n", array_source(&data, ArrayLang::Go, "DATA", 2));
+    }
+
+    #[test]
+    fn test_array_source_python() {
+        init();
+
+        let data: [u8; 4] = [0, 1, 2, 3];
+        assert_eq!("DATA = bytes([\n    0, 1,\n    2, 3,\n])\n", array_source(&data, ArrayLang::Python, "DATA", 2));
+    }
+
     #[test]
     fn test_byte_to_char() {
         init();
@@ -196,27 +659,60 @@ mod tests{
         assert_eq!("0x00000010", address_to_hex( 16));
         assert_eq!("0x000000ff", address_to_hex(255));
         assert_eq!("0xdeadbeef", address_to_hex(3735928559));
+        assert_eq!("0x100000000", address_to_hex(4294967296));
+    }
+
+    #[test]
+    fn test_parse_size() {
+        init();
+
+        assert_eq!(Ok(0), parse_size("0"));
+        assert_eq!(Ok(4096), parse_size("0x1000"));
+        assert_eq!(Ok(4096), parse_size("4K"));
+        assert_eq!(Ok(1048576), parse_size("1M"));
+        assert_eq!(Ok(1073741824), parse_size("1G"));
+        assert!(parse_size("not-a-number").is_err());
+        assert!(parse_size("99999999999999999G").is_err());
+    }
+
+    #[test]
+    fn test_color_enabled() {
+        init();
+
+        assert!(color_enabled(ColorMode::Always, false));
+        assert!(!color_enabled(ColorMode::Never, true));
+        assert_eq!(atty::is(atty::Stream::Stdout), color_enabled(ColorMode::Auto, true));
+        assert!(!color_enabled(ColorMode::Auto, false));
+    }
+
+    #[test]
+    fn test_detect_signature() {
+        init();
+
+        assert_eq!(Some("PNG image"), detect_signature(&[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00]));
+        assert_eq!(Some("GZIP archive"), detect_signature(&[0x1F, 0x8B, 0x08, 0x00]));
+        assert_eq!(None, detect_signature(&[0x00, 0x01, 0x02, 0x03]));
     }
 
     #[test]
     fn test_header_8() {
         init();
 
-        assert_eq!("            00 01 02 03 04 05 06 07          \n", locations_header(8));
+        assert_eq!("            00 01 02 03 04 05 06 07          \n", locations_header(8, NumericFormat::HexUpper));
     }
 
     #[test]
     fn test_header_16() {
         init();
 
-        assert_eq!("            00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f                  \n", locations_header(16));
+        assert_eq!("            00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f                  \n", locations_header(16, NumericFormat::HexUpper));
     }
 
     #[test]
     fn test_header_32() {
         init();
 
-        assert_eq!("            00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  10 11 12 13 14 15 16 17  18 19 1a 1b 1c 1d 1e 1f                                  \n", locations_header(32));
+        assert_eq!("            00 01 02 03 04 05 06 07  08 09 0a 0b 0c 0d 0e 0f  10 11 12 13 14 15 16 17  18 19 1a 1b 1c 1d 1e 1f                                  \n", locations_header(32, NumericFormat::HexUpper));
     }
 
     #[test]
@@ -224,7 +720,7 @@ mod tests{
         init();
 
         let data: [u8; 16] = [48+0,48+1,48+2,48+3,48+4,48+5,48+6,48+7,48+8,48+9,55+10,55+11,55+12,55+13,55+14,55+15];
-        assert_eq!("0xdeadbeef  30 31 32 33 34 35 36 37  38 39 41 42 43 44 45 46  0123456789ABCDEF\n", data_row(3735928559, &data, 16));
+        assert_eq!("0xdeadbeef  30 31 32 33 34 35 36 37  38 39 41 42 43 44 45 46  0123456789ABCDEF\n", data_row(3735928559, &data, 16, false, NumericFormat::HexUpper));
     }
 
     #[test]
@@ -232,10 +728,35 @@ mod tests{
         init();
 
         let data: [u8; 4] = [48+0,48+1,48+2,48+3];
-        assert_eq!("0xdeadbeef  30 31 32 33 .. .. .. ..  .. .. .. .. .. .. .. ..  0123............\n", data_row(3735928559, &data, 16));
+        assert_eq!("0xdeadbeef  30 31 32 33 .. .. .. ..  .. .. .. .. .. .. .. ..  0123............\n", data_row(3735928559, &data, 16, false, NumericFormat::HexUpper));
 
         let data: [u8; 12] = [48+0,48+1,48+2,48+3,48+4,48+5,48+6,48+7,48+8,48+9,55+10,55+11];
-        assert_eq!("0xdeadbeef  30 31 32 33 34 35 36 37  38 39 41 42 .. .. .. ..  0123456789AB....\n", data_row(3735928559, &data, 16));
+        assert_eq!("0xdeadbeef  30 31 32 33 34 35 36 37  38 39 41 42 .. .. .. ..  0123456789AB....\n", data_row(3735928559, &data, 16, false, NumericFormat::HexUpper));
+    }
+
+    #[test]
+    fn test_row_colorized() {
+        init();
+
+        let data: [u8; 2] = [0, 65];
+        let expected = "0xdeadbeef  \x1b[2m00\x1b[0m \x1b[32m41\x1b[0m .. .. .. .. .. ..  \x1b[2m.\x1b[0m\x1b[32mA\x1b[0m......\n";
+        assert_eq!(expected, data_row(3735928559, &data, 8, true, NumericFormat::HexUpper));
+    }
+
+    #[test]
+    fn test_short_row_octal() {
+        init();
+
+        let data: [u8; 4] = [48+0,48+1,48+2,48+3];
+        assert_eq!("0xdeadbeef  060 061 062 063 ... ... ... ...  ... ... ... ... ... ... ... ...  0123............\n", data_row(3735928559, &data, 16, false, NumericFormat::Octal));
+    }
+
+    #[test]
+    fn test_short_row_binary() {
+        init();
+
+        let data: [u8; 4] = [48+0,48+1,48+2,48+3];
+        assert_eq!("0xdeadbeef  00110000 00110001 00110010 00110011 ........ ........ ........ ........  ........ ........ ........ ........ ........ ........ ........ ........  0123............\n", data_row(3735928559, &data, 16, false, NumericFormat::Binary));
     }
 
     #[test]